@@ -0,0 +1,59 @@
+//! A structured error type for `.vxd` parsing, replacing the old
+//! `io::Error`-only failure mode and the silent fallback to `VxdValue::Null`
+//! on malformed values. Every variant that originates from a specific line
+//! of input carries its 1-based line number so a caller can point a user
+//! straight at the offending declaration.
+
+use std::fmt;
+use std::io;
+
+/// Everything that can go wrong while parsing `.vxd` content.
+#[derive(Debug)]
+pub enum VxdError {
+    /// The underlying reader or file could not be read.
+    Io(io::Error),
+    /// A declaration named a type the parser doesn't know how to handle.
+    UnsupportedType { line: usize, type_name: String },
+    /// A declaration's value could not be parsed as its declared type.
+    ParseValue {
+        line: usize,
+        key: String,
+        type_name: String,
+        raw: String,
+    },
+    /// A line looked like a declaration but didn't match the expected
+    /// `NAME: type = value;` grammar.
+    Syntax { line: usize, text: String },
+}
+
+impl fmt::Display for VxdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VxdError::Io(e) => write!(f, "I/O error: {}", e),
+            VxdError::UnsupportedType { line, type_name } => {
+                write!(f, "line {}: unsupported type '{}'", line, type_name)
+            }
+            VxdError::ParseValue { line, key, type_name, raw } => {
+                write!(f, "line {}: could not parse '{}' as {} for key '{}'", line, raw, type_name, key)
+            }
+            VxdError::Syntax { line, text } => {
+                write!(f, "line {}: invalid declaration syntax: '{}'", line, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VxdError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VxdError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for VxdError {
+    fn from(e: io::Error) -> Self {
+        VxdError::Io(e)
+    }
+}
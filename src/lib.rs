@@ -1,14 +1,27 @@
 //! This module provides a parser for `.vxd` files, extracting key-value pairs
 //! based on their declared types. Supported types include string, integers (i32, i64),
-//! unsigned integers (u32, u64), floating point (f32, f64), boolean, char, and null values.
-//! The parser ensures that values are correctly parsed into the `VxdValue` enum and stored
-//! in a hash map for further usage.
+//! unsigned integers (u32, u64), floating point (f32, f64), boolean, char, null values,
+//! and `[type]` arrays of any of the above. The parser ensures that values are correctly
+//! parsed into the `VxdValue` enum and stored in a hash map for further usage.
+//! Declarations may also be grouped under `[section_name]` headers, in which case they
+//! are exposed separately through `VxdeParser::get_sections` instead of `get_variables`.
 
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{ self, BufRead };
 
+pub mod error;
+pub mod registry;
+pub mod schema;
+pub mod writer;
+
+use registry::CustomTypeRegistry;
+
+pub use error::VxdError;
+pub use registry::VxdeParserBuilder;
+pub use schema::{ VxdAllowedValues, VxdBound, VxdFieldSchema, VxdRange, VxdSchema, VxdType, VxdViolation };
+
 /// Enum to represent different value types that can be parsed from a `.vxd` file.
 /// The `Null` variant represents a missing or undefined value.
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +35,19 @@ pub enum VxdValue {
     F64(f64),
     Bool(bool),
     Char(char),
+    Array(Vec<VxdValue>),
+    /// A value produced by a closure registered via
+    /// [`VxdeParser::with_custom_type`], holding its declared type name
+    /// (e.g. `"datetime"`) and the closure's canonical string rendering.
+    ///
+    /// This holds a `String` rather than a `Box<dyn Any>`: `VxdValue` derives
+    /// `Clone` and `PartialEq`, and `dyn Any` supports neither without extra
+    /// downcasting machinery this crate doesn't otherwise use. A caller that
+    /// needs the typed value back (e.g. a real `Ipv4Addr`) re-parses the
+    /// string with the same logic their closure used to produce it; the
+    /// string is kept canonical (e.g. `Ipv4Addr`'s own `Display`) so that
+    /// re-parse round-trips cleanly.
+    Custom(String, String),
     Null, // Representing null values
 }
 
@@ -33,13 +59,46 @@ impl VxdValue {
 /// A struct to hold the parsed key-value pairs from a `.vxd` file.
 /// It stores the variables in a `HashMap` where keys are strings representing the variable names,
 /// and values are of type `VxdValue` representing the parsed value.
+///
+/// Declarations under a `[section_name]` header are grouped separately and
+/// exposed through [`VxdeParser::get_sections`] instead of [`VxdeParser::get_variables`].
+#[derive(Debug)]
 pub struct VxdeParser {
     variables: HashMap<String, VxdValue>,
+    sections: HashMap<String, HashMap<String, VxdValue>>,
 }
 
 impl VxdeParser {
+    /// Builds a [`VxdeParserBuilder`] with a custom type parser registered for
+    /// `name`, the way `clap::value_parser!` registers a `TypedValueParser`.
+    /// Chain more `.with_custom_type(..)` calls, then parse with `.from_file(..)`,
+    /// `.from_str(..)`, or `.from_reader(..)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let parser = VxdeParser::with_custom_type("ipv4", |raw| {
+    ///     raw.parse::<std::net::Ipv4Addr>()
+    ///         .map(|ip| VxdValue::Custom("ipv4".to_string(), ip.to_string()))
+    ///         .map_err(|e| e.to_string())
+    /// })
+    /// .from_file("config.vxd")?;
+    /// ```
+    pub fn with_custom_type(name: impl Into<String>, f: impl Fn(&str) -> Result<VxdValue, String> + 'static) -> VxdeParserBuilder {
+        VxdeParserBuilder::new().with_custom_type(name, f)
+    }
+
+    pub(crate) fn from_parts(variables: HashMap<String, VxdValue>, sections: HashMap<String, HashMap<String, VxdValue>>) -> Self {
+        VxdeParser { variables, sections }
+    }
+
     /// Reads a `.vxd` file and parses its content into key-value pairs.
     ///
+    /// Malformed values fall back to `VxdValue::Null`; use
+    /// [`VxdeParser::from_file_strict`] to fail on the first bad value instead,
+    /// or [`VxdeParser::from_file_collecting`] to keep the `Null` fallback
+    /// while also getting back what went wrong.
+    ///
     /// # Arguments
     ///
     /// * `file_path` - The path to the `.vxd` file to be parsed.
@@ -47,7 +106,7 @@ impl VxdeParser {
     /// # Returns
     ///
     /// * `Ok(Self)` containing the parsed `VxdeParser` with key-value pairs if parsing succeeds.
-    /// * `Err(io::Error)` if there is an issue reading the file or parsing its contents.
+    /// * `Err(VxdError)` if the file can't be read or contains an unsupported type or a syntax error.
     ///
     /// # Example
     ///
@@ -58,97 +117,90 @@ impl VxdeParser {
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
     /// ```
-    pub fn from_file(file_path: &str) -> io::Result<Self> {
-        let mut variables = HashMap::new();
+    pub fn from_file(file_path: &str) -> Result<Self, VxdError> {
+        let file = File::open(file_path)?;
+        Self::from_reader(io::BufReader::new(file))
+    }
 
+    /// Like [`VxdeParser::from_file`], but returns `Err(VxdError::ParseValue { .. })`
+    /// on the first value that can't be parsed as its declared type, instead of
+    /// silently falling back to `VxdValue::Null`.
+    pub fn from_file_strict(file_path: &str) -> Result<Self, VxdError> {
         let file = File::open(file_path)?;
-        let reader = io::BufReader::new(file);
-
-        // Regex for matching valid variable declarations
-        let re = Regex::new(r"(?m)^\s*([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(string|i32|i64|u32|u64|f32|f64|bool|char)\s*(=\s*([^;]*))?\s*;").unwrap();
-
-        // Iterate through the lines in the file
-        for line in reader.lines() {
-            let line = line?;
-
-            // Check if the line matches the pattern of a valid declaration
-            for caps in re.captures_iter(&line) {
-                let name = &caps[1];
-                let vtype = &caps[2];
-                let value = caps.get(4).map_or("", |m| m.as_str()).trim();
-
-                let parsed_value = match vtype {
-                    "string" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            VxdValue::String(value.to_string())
-                        }
-                    },
-                    "i32" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<i32>().ok().map(VxdValue::I32).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "i64" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<i64>().ok().map(VxdValue::I64).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "u32" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<u32>().ok().map(VxdValue::U32).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "u64" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<u64>().ok().map(VxdValue::U64).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "f32" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<f32>().ok().map(VxdValue::F32).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "f64" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<f64>().ok().map(VxdValue::F64).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "bool" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.parse::<bool>().ok().map(VxdValue::Bool).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    "char" => {
-                        if value == "null" || value.is_empty() {
-                            VxdValue::Null
-                        } else {
-                            value.chars().next().map(VxdValue::Char).unwrap_or(VxdValue::Null)
-                        }
-                    },
-                    _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Unsupported type: {}", vtype))),
-                };
-
-                variables.insert(name.to_string(), parsed_value);
-            }
-        }
+        Self::from_reader_strict(io::BufReader::new(file))
+    }
 
-        Ok(VxdeParser { variables })
+    /// Like [`VxdeParser::from_file`], but also returns every `VxdError` collected
+    /// while parsing alongside the successfully parsed map, instead of discarding them.
+    pub fn from_file_collecting(file_path: &str) -> Result<(Self, Vec<VxdError>), VxdError> {
+        let file = File::open(file_path)?;
+        Self::from_reader_collecting(io::BufReader::new(file))
+    }
+
+    /// Parses `.vxd` content held in a string, without touching the filesystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The `.vxd` text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` containing the parsed `VxdeParser` with key-value pairs if parsing succeeds.
+    /// * `Err(VxdError)` if the content contains an unsupported type or a syntax error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let parser = VxdeParser::from_str("PORT: u32 = 8080;")?;
+    /// ```
+    #[allow(clippy::should_implement_trait)] // Intentionally mirrors `from_file`/`from_reader`, not `std::str::FromStr`.
+    pub fn from_str(content: &str) -> Result<Self, VxdError> {
+        Self::from_reader(content.as_bytes())
+    }
+
+    /// Like [`VxdeParser::from_str`], but fails on the first bad value instead
+    /// of falling back to `VxdValue::Null`. See [`VxdeParser::from_file_strict`].
+    pub fn from_str_strict(content: &str) -> Result<Self, VxdError> {
+        Self::from_reader_strict(content.as_bytes())
+    }
+
+    /// Parses `.vxd` content from any buffered reader, the shared line-parsing
+    /// loop behind [`VxdeParser::from_file`] and [`VxdeParser::from_str`].
+    /// Malformed values fall back to `VxdValue::Null` and are otherwise ignored;
+    /// use [`VxdeParser::from_reader_collecting`] to also get back what went wrong.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A `BufRead` yielding the `.vxd` content line by line.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` containing the parsed `VxdeParser` with key-value pairs if parsing succeeds.
+    /// * `Err(VxdError)` if the content can't be read or contains an unsupported type or a syntax error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let parser = VxdeParser::from_reader(io::BufReader::new(some_reader))?;
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, VxdError> {
+        let (variables, sections, _errors) = parse_lines(reader, false, &CustomTypeRegistry::default())?;
+        Ok(VxdeParser { variables, sections })
+    }
+
+    /// Like [`VxdeParser::from_reader`], but returns `Err(VxdError::ParseValue { .. })`
+    /// on the first value that can't be parsed as its declared type.
+    pub fn from_reader_strict<R: BufRead>(reader: R) -> Result<Self, VxdError> {
+        let (variables, sections, _errors) = parse_lines(reader, true, &CustomTypeRegistry::default())?;
+        Ok(VxdeParser { variables, sections })
+    }
+
+    /// Like [`VxdeParser::from_reader`], but also returns every `VxdError`
+    /// collected while parsing (malformed values that fell back to `Null`)
+    /// alongside the successfully parsed map.
+    pub fn from_reader_collecting<R: BufRead>(reader: R) -> Result<(Self, Vec<VxdError>), VxdError> {
+        let (variables, sections, errors) = parse_lines(reader, false, &CustomTypeRegistry::default())?;
+        Ok((VxdeParser { variables, sections }, errors))
     }
 
     /// Returns the parsed variables stored in the `variables` HashMap.
@@ -165,6 +217,237 @@ impl VxdeParser {
     pub fn get_variables(&self) -> &HashMap<String, VxdValue> {
         &self.variables
     }
+
+    /// Returns the named sections parsed from `[section_name]`-delimited groups.
+    ///
+    /// # Returns
+    ///
+    /// * A reference to the section name to variable map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let sections = parser.get_sections();
+    /// ```
+    pub fn get_sections(&self) -> &HashMap<String, HashMap<String, VxdValue>> {
+        &self.sections
+    }
+
+    /// Validates the parsed variables against `schema`, the way clap's
+    /// `value_parser!(u16).range(3000..)` validates a single argument.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every declared field satisfies its constraints.
+    /// * `Err(Vec<VxdViolation>)` listing every violation found, so callers
+    ///   can report all of them at once instead of failing on the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let schema = VxdSchema::new().field("PORT", VxdFieldSchema::new(VxdType::U32).required(true));
+    /// parser.validate(&schema)?;
+    /// ```
+    pub fn validate(&self, schema: &VxdSchema) -> Result<(), Vec<VxdViolation>> {
+        let violations = schema.check(&self.variables);
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+
+    /// Renders the parsed variables and writes them to `file_path`,
+    /// overwriting any existing file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// parser.to_file("config.vxd")?;
+    /// ```
+    pub fn to_file(&self, file_path: &str) -> io::Result<()> {
+        writer::to_file_with_sections(&self.variables, &self.sections, file_path)
+    }
+}
+
+/// Renders the parsed variables and sections back into canonical `.vxd` text
+/// via [`writer::to_string_with_sections`], so `parser.to_string()` round-trips
+/// through [`VxdeParser::from_file`].
+impl std::fmt::Display for VxdeParser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", writer::to_string_with_sections(&self.variables, &self.sections))
+    }
+}
+
+/// Splits a `[type]` array's raw value on `,`, skipping commas that were
+/// escaped (as `\,`) by [`writer::escape_string`] so a `string` array element
+/// containing a literal comma isn't split in two. Escape sequences are left
+/// intact in the returned pieces; [`parse_scalar`] unescapes them per element.
+fn split_unescaped_commas(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            ',' => {
+                parts.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+
+    parts
+}
+
+/// Parses a single scalar value of the named type (`string`, `i32`, ... or a
+/// type registered via [`VxdeParser::with_custom_type`]), the way a `.vxd`
+/// declaration or an element of a `[type]` array does. Shared by
+/// [`parse_lines`] for both top-level declarations and array elements.
+fn parse_scalar(type_name: &str, raw: &str, line_no: usize, key: &str, custom_types: &CustomTypeRegistry) -> Result<VxdValue, VxdError> {
+    if raw == "null" || raw.is_empty() {
+        return Ok(VxdValue::Null);
+    }
+
+    let err = || VxdError::ParseValue {
+        line: line_no, key: key.to_string(), type_name: type_name.to_string(), raw: raw.to_string(),
+    };
+
+    match type_name {
+        "string" => Ok(VxdValue::String(writer::unescape_string(raw))),
+        "i32" => raw.parse::<i32>().map(VxdValue::I32).map_err(|_| err()),
+        "i64" => raw.parse::<i64>().map(VxdValue::I64).map_err(|_| err()),
+        "u32" => raw.parse::<u32>().map(VxdValue::U32).map_err(|_| err()),
+        "u64" => raw.parse::<u64>().map(VxdValue::U64).map_err(|_| err()),
+        "f32" => raw.parse::<f32>().map(VxdValue::F32).map_err(|_| err()),
+        "f64" => raw.parse::<f64>().map(VxdValue::F64).map_err(|_| err()),
+        "bool" => raw.parse::<bool>().map(VxdValue::Bool).map_err(|_| err()),
+        "char" => raw.chars().next().map(VxdValue::Char).ok_or_else(err),
+        other => match custom_types.get(other) {
+            Some(parser) => parser(&writer::unescape_string(raw)).map_err(|_| err()),
+            None => Err(VxdError::UnsupportedType { line: line_no, type_name: other.to_string() }),
+        },
+    }
+}
+
+/// Like [`parse_lines`], but also consults `custom_types` for any type name
+/// that isn't one of the built-ins. Used by [`VxdeParserBuilder`](crate::registry::VxdeParserBuilder).
+#[allow(clippy::type_complexity)]
+pub(crate) fn parse_with_registry<R: BufRead>(
+    reader: R,
+    strict: bool,
+    custom_types: &CustomTypeRegistry,
+) -> Result<(HashMap<String, VxdValue>, HashMap<String, HashMap<String, VxdValue>>, Vec<VxdError>), VxdError> {
+    parse_lines(reader, strict, custom_types)
+}
+
+/// Parses every line yielded by `reader`, threading a 1-based line counter
+/// through for error reporting. In strict mode, the first `ParseValue` error
+/// is returned immediately; otherwise it is collected into the returned
+/// `Vec<VxdError>` and the key falls back to `VxdValue::Null`.
+/// `UnsupportedType` and `Syntax` errors are always fatal, in both modes.
+///
+/// Declarations are grouped into the top-level map until a `[section_name]`
+/// header line starts a named section; a blank line ends the current section
+/// and returns subsequent declarations to the top-level map. Any type name
+/// that isn't one of the built-ins is looked up in `custom_types`.
+#[allow(clippy::type_complexity)]
+fn parse_lines<R: BufRead>(
+    reader: R,
+    strict: bool,
+    custom_types: &CustomTypeRegistry,
+) -> Result<(HashMap<String, VxdValue>, HashMap<String, HashMap<String, VxdValue>>, Vec<VxdError>), VxdError> {
+    let mut variables = HashMap::new();
+    let mut sections: HashMap<String, HashMap<String, VxdValue>> = HashMap::new();
+    let mut errors = Vec::new();
+    let mut current_section: Option<String> = None;
+
+    // Regex for matching valid variable declarations; the type is either a base
+    // type (`i32`, `string`, ...), a `[base_type]` array of that type, or any
+    // other identifier handled by a registered custom type parser. The value
+    // is any run of non-`;` characters or `\`-escaped pairs (so an escaped
+    // `\;` produced by `writer::escape_string` doesn't end the declaration
+    // early), up to the first unescaped `;` terminator.
+    let re = Regex::new(
+        r"(?m)^\s*([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(\[[A-Za-z_][A-Za-z0-9_]*\]|[A-Za-z_][A-Za-z0-9_]*)\s*(=\s*((?:[^;\\]|\\.)*))?\s*;",
+    ).unwrap();
+    // A looser match used only to tell "not a declaration at all" (blank line, comment, ...)
+    // apart from "looks like a declaration but is malformed".
+    let declaration_like = Regex::new(r"^\s*[A-Za-z_][A-Za-z0-9_]*\s*:").unwrap();
+    // `[section_name]` on a line by itself starts a new named section.
+    let section_header = Regex::new(r"^\s*\[([A-Za-z_][A-Za-z0-9_]*)\]\s*$").unwrap();
+
+    for (line_no, line) in reader.lines().enumerate() {
+        let line_no = line_no + 1; // 1-based line numbers
+        let line = line?;
+
+        if let Some(caps) = section_header.captures(&line) {
+            let name = caps[1].to_string();
+            sections.entry(name.clone()).or_default();
+            current_section = Some(name);
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            current_section = None;
+            continue;
+        }
+
+        let mut matched_any = false;
+
+        // Check if the line matches the pattern of a valid declaration
+        for caps in re.captures_iter(&line) {
+            matched_any = true;
+
+            let name = &caps[1];
+            let vtype = &caps[2];
+            let value = caps.get(4).map_or("", |m| m.as_str()).trim();
+
+            let parsed_value: Result<VxdValue, VxdError> = if let Some(elem_type) = vtype.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                if value == "[]" {
+                    Ok(VxdValue::Array(Vec::new()))
+                } else if value == "null" || value.is_empty() {
+                    Ok(VxdValue::Null)
+                } else {
+                    split_unescaped_commas(value)
+                        .iter()
+                        .map(|elem| parse_scalar(elem_type, elem.trim(), line_no, name, custom_types))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(VxdValue::Array)
+                }
+            } else {
+                parse_scalar(vtype, value, line_no, name, custom_types)
+            };
+
+            let target = match &current_section {
+                Some(section_name) => sections.entry(section_name.clone()).or_default(),
+                None => &mut variables,
+            };
+
+            match parsed_value {
+                Ok(value) => {
+                    target.insert(name.to_string(), value);
+                }
+                Err(e @ VxdError::UnsupportedType { .. }) => return Err(e),
+                Err(e) => {
+                    if strict {
+                        return Err(e);
+                    }
+                    errors.push(e);
+                    target.insert(name.to_string(), VxdValue::Null);
+                }
+            }
+        }
+
+        if !matched_any && declaration_like.is_match(&line) {
+            return Err(VxdError::Syntax { line: line_no, text: line.trim().to_string() });
+        }
+    }
+
+    Ok((variables, sections, errors))
 }
 
 /// Function to demonstrate how to print the parsed variables from a `.vxd` file.
@@ -191,6 +474,8 @@ pub fn print_variables(variables: &HashMap<String, VxdValue>) {
             VxdValue::F64(val) => println!("{}: f64 = {}", name, val),
             VxdValue::Bool(val) => println!("{}: bool = {}", name, val),
             VxdValue::Char(val) => println!("{}: char = {}", name, val),
+            VxdValue::Array(val) => println!("{}: array = {:?}", name, val),
+            VxdValue::Custom(type_name, val) => println!("{}: {} = {}", name, type_name, val),
             VxdValue::Null => println!("{}: null", name),
         }
     }
@@ -248,5 +533,108 @@ mod tests {
             }
         }
     }
-    
+
+    /// `from_str_strict` should fail on the first malformed value instead of
+    /// silently falling back to `VxdValue::Null`.
+    #[test]
+    fn test_strict_mode_reports_parse_value_error() {
+        let result = VxdeParser::from_str_strict("PORT: u32 = not_a_number;");
+
+        match result {
+            Err(VxdError::ParseValue { key, type_name, .. }) => {
+                assert_eq!(key, "PORT");
+                assert_eq!(type_name, "u32");
+            }
+            other => panic!("Expected a ParseValue error, got {:?}", other),
+        }
+    }
+
+    /// `from_str` (lenient) should keep falling back to `Null`, while
+    /// `from_reader_collecting`'s returned `Vec<VxdError>` records what happened.
+    #[test]
+    fn test_collecting_mode_keeps_null_fallback_and_reports_errors() {
+        let (parser, errors) = VxdeParser::from_reader_collecting("PORT: u32 = not_a_number;".as_bytes())
+            .expect("unsupported-type/syntax errors should not occur here");
+
+        assert_eq!(parser.get_variables()["PORT"], VxdValue::Null);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], VxdError::ParseValue { .. }));
+    }
+
+    /// An `[elem_type]` declaration should parse into a `VxdValue::Array` of
+    /// the corresponding scalar values.
+    #[test]
+    fn test_parses_array_values() {
+        let parser = VxdeParser::from_str("PORTS: [u32] = 80, 443, 8080;").expect("should parse");
+
+        assert_eq!(
+            parser.get_variables()["PORTS"],
+            VxdValue::Array(vec![VxdValue::U32(80), VxdValue::U32(443), VxdValue::U32(8080)])
+        );
+    }
+
+    /// A hand-written `= null;` means `VxdValue::Null` for a `string` field
+    /// exactly like it does for every other type, matching files written
+    /// before this crate escaped a literal string `"null"` as `\0null`.
+    #[test]
+    fn test_hand_written_null_literal_is_null_for_every_type() {
+        let parser = VxdeParser::from_str("NAME: string = null;\nPORT: u32 = null;").expect("should parse");
+
+        assert_eq!(parser.get_variables()["NAME"], VxdValue::Null);
+        assert_eq!(parser.get_variables()["PORT"], VxdValue::Null);
+    }
+
+    /// Declarations under a `[section_name]` header should be grouped separately
+    /// from the top-level variables, and a blank line should end the section.
+    #[test]
+    fn test_groups_declarations_under_section_headers() {
+        let content = "\
+HOST: string = shared;
+
+[db]
+PORT: u32 = 5432;
+
+AFTER: string = top_level;
+";
+        let parser = VxdeParser::from_str(content).expect("should parse");
+
+        assert_eq!(parser.get_variables()["HOST"], VxdValue::String("shared".to_string()));
+        assert_eq!(parser.get_variables()["AFTER"], VxdValue::String("top_level".to_string()));
+        assert!(!parser.get_variables().contains_key("PORT"));
+
+        assert_eq!(parser.get_sections()["db"]["PORT"], VxdValue::U32(5432));
+    }
+
+    /// A type name registered via `with_custom_type` should be dispatched to
+    /// the registered closure instead of producing `UnsupportedType`.
+    #[test]
+    fn test_custom_type_parser_is_used() {
+        let parser = VxdeParser::with_custom_type("ipv4", |raw| {
+            raw.parse::<std::net::Ipv4Addr>()
+                .map(|ip| VxdValue::Custom("ipv4".to_string(), ip.to_string()))
+                .map_err(|e| e.to_string())
+        })
+        .from_str("ADDR: ipv4 = 10.0.0.1;")
+        .expect("should parse with the registered custom type");
+
+        assert_eq!(
+            parser.get_variables()["ADDR"],
+            VxdValue::Custom("ipv4".to_string(), "10.0.0.1".to_string())
+        );
+    }
+
+    /// A type name that isn't built in and wasn't registered should still
+    /// fail with `UnsupportedType`, exactly as before custom types existed.
+    #[test]
+    fn test_unregistered_type_is_still_unsupported() {
+        let result = VxdeParser::from_str("STARTED: datetime = 2024-01-01T00:00:00Z;");
+
+        match result {
+            Err(VxdError::UnsupportedType { type_name, .. }) => {
+                assert_eq!(type_name, "datetime");
+            }
+            Ok(_) => panic!("Expected an UnsupportedType error, but parsing succeeded"),
+            Err(other) => panic!("Expected an UnsupportedType error, got {:?}", other),
+        }
+    }
 }
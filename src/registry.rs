@@ -0,0 +1,78 @@
+//! An open registry of custom type parsers, mirroring clap's `TypedValueParser`
+//! abstraction: a type name maps to a closure that converts a raw `&str` into
+//! a [`VxdValue`]. Built-in types (`string`, `i32`, ...) are always handled
+//! first; a registered closure only runs for type names the parser doesn't
+//! already know, so this is purely additive on top of [`VxdeParser`](crate::VxdeParser).
+//!
+//! Closures return [`VxdValue::Custom`], which stores the canonical rendering
+//! as a `String` rather than a `Box<dyn Any>` holding the real typed value —
+//! see that variant's doc comment for why.
+
+use crate::{ VxdError, VxdValue, VxdeParser };
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub(crate) type CustomTypeFn = dyn Fn(&str) -> Result<VxdValue, String>;
+
+/// Holds the closures registered via [`VxdeParserBuilder::with_custom_type`],
+/// keyed by the `.vxd` type name they handle (e.g. `"datetime"`, `"ipv4"`).
+#[derive(Default)]
+pub(crate) struct CustomTypeRegistry {
+    parsers: HashMap<String, Box<CustomTypeFn>>,
+}
+
+impl CustomTypeRegistry {
+    pub(crate) fn get(&self, name: &str) -> Option<&CustomTypeFn> {
+        self.parsers.get(name).map(|f| f.as_ref())
+    }
+}
+
+/// Builds a [`VxdeParser`] with one or more custom type parsers registered,
+/// the way `clap::Command::new(..)` is built up before parsing.
+///
+/// # Example
+///
+/// ```rust
+/// let parser = VxdeParser::with_custom_type("ipv4", |raw| {
+///     raw.parse::<std::net::Ipv4Addr>()
+///         .map(|ip| VxdValue::Custom("ipv4".to_string(), ip.to_string()))
+///         .map_err(|e| e.to_string())
+/// })
+/// .from_file("config.vxd")?;
+/// ```
+#[derive(Default)]
+pub struct VxdeParserBuilder {
+    registry: CustomTypeRegistry,
+}
+
+impl VxdeParserBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `f` to parse declarations of type `name` (e.g. `STARTED: datetime = ...;`).
+    /// Only applies to type names that aren't already one of the built-in types.
+    pub fn with_custom_type(mut self, name: impl Into<String>, f: impl Fn(&str) -> Result<VxdValue, String> + 'static) -> Self {
+        self.registry.parsers.insert(name.into(), Box::new(f));
+        self
+    }
+
+    /// Reads a `.vxd` file, running any registered custom type parsers
+    /// alongside the built-in types. See [`VxdeParser::from_file`].
+    pub fn from_file(&self, file_path: &str) -> Result<VxdeParser, VxdError> {
+        let file = std::fs::File::open(file_path)?;
+        self.from_reader(std::io::BufReader::new(file))
+    }
+
+    /// Parses `.vxd` content held in a string. See [`VxdeParser::from_str`].
+    pub fn from_str(&self, content: &str) -> Result<VxdeParser, VxdError> {
+        self.from_reader(content.as_bytes())
+    }
+
+    /// Parses `.vxd` content from any buffered reader. See [`VxdeParser::from_reader`].
+    pub fn from_reader<R: BufRead>(&self, reader: R) -> Result<VxdeParser, VxdError> {
+        crate::parse_with_registry(reader, false, &self.registry).map(|(variables, sections, _errors)| {
+            VxdeParser::from_parts(variables, sections)
+        })
+    }
+}
@@ -0,0 +1,359 @@
+//! A lightweight schema/constraint layer for validating values parsed by
+//! [`VxdeParser`](crate::VxdeParser). A [`VxdSchema`] declares, per key, the
+//! expected [`VxdValue`] type, an optional numeric range, an optional set of
+//! allowed values, and whether the key must be present. Running
+//! [`VxdeParser::validate`](crate::VxdeParser::validate) checks the parsed
+//! variables against the schema and reports every violation it finds.
+
+use crate::VxdValue;
+use std::collections::HashMap;
+
+/// The type a field is expected to hold, independent of any particular value.
+///
+/// `Custom` carries the registered type name (e.g. `"ipv4"`) that a
+/// [`VxdValue::Custom`] must have been parsed as to satisfy it; an
+/// unqualified `Custom` match isn't offered since that would accept any
+/// custom type interchangeably.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VxdType {
+    String,
+    I32,
+    I64,
+    U32,
+    U64,
+    F32,
+    F64,
+    Bool,
+    Char,
+    Array,
+    Custom(String),
+}
+
+impl VxdType {
+    /// Returns whether `value` is an instance of this type (or `Null`, which
+    /// is only acceptable for non-required fields and is checked separately).
+    fn matches(&self, value: &VxdValue) -> bool {
+        matches!(
+            (self, value),
+            (VxdType::String, VxdValue::String(_))
+                | (VxdType::I32, VxdValue::I32(_))
+                | (VxdType::I64, VxdValue::I64(_))
+                | (VxdType::U32, VxdValue::U32(_))
+                | (VxdType::U64, VxdValue::U64(_))
+                | (VxdType::F32, VxdValue::F32(_))
+                | (VxdType::F64, VxdValue::F64(_))
+                | (VxdType::Bool, VxdValue::Bool(_))
+                | (VxdType::Char, VxdValue::Char(_))
+                | (VxdType::Array, VxdValue::Array(_))
+        ) || matches!((self, value), (VxdType::Custom(expected), VxdValue::Custom(actual, _)) if expected == actual)
+    }
+}
+
+/// An inclusive or exclusive bound used by [`VxdRange`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VxdBound {
+    Inclusive(f64),
+    Exclusive(f64),
+}
+
+impl VxdBound {
+    fn satisfied_as_min(self, value: f64) -> bool {
+        match self {
+            VxdBound::Inclusive(bound) => value >= bound,
+            VxdBound::Exclusive(bound) => value > bound,
+        }
+    }
+
+    fn satisfied_as_max(self, value: f64) -> bool {
+        match self {
+            VxdBound::Inclusive(bound) => value <= bound,
+            VxdBound::Exclusive(bound) => value < bound,
+        }
+    }
+}
+
+/// A numeric range constraint, checked against any of the `I32`/`I64`/`U32`/
+/// `U64`/`F32`/`F64` variants by comparing their values as `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VxdRange {
+    pub min: Option<VxdBound>,
+    pub max: Option<VxdBound>,
+}
+
+impl VxdRange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(mut self, bound: VxdBound) -> Self {
+        self.min = Some(bound);
+        self
+    }
+
+    pub fn max(mut self, bound: VxdBound) -> Self {
+        self.max = Some(bound);
+        self
+    }
+
+    /// Extracts a numeric value as `f64` for bounds checking, or `None` if
+    /// `value` is not one of the numeric variants.
+    fn numeric_value(value: &VxdValue) -> Option<f64> {
+        match *value {
+            VxdValue::I32(v) => Some(v as f64),
+            VxdValue::I64(v) => Some(v as f64),
+            VxdValue::U32(v) => Some(v as f64),
+            VxdValue::U64(v) => Some(v as f64),
+            VxdValue::F32(v) => Some(v as f64),
+            VxdValue::F64(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    fn contains(&self, value: &VxdValue) -> bool {
+        match Self::numeric_value(value) {
+            Some(v) => {
+                self.min.is_none_or(|bound| bound.satisfied_as_min(v))
+                    && self.max.is_none_or(|bound| bound.satisfied_as_max(v))
+            }
+            None => true, // Non-numeric values are not constrained by a range.
+        }
+    }
+}
+
+/// The set of values a field is allowed to take, mirroring clap's
+/// `value_parser(["always", "auto", "never"])`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VxdAllowedValues {
+    Strings(Vec<String>),
+    Chars(Vec<char>),
+}
+
+impl VxdAllowedValues {
+    fn contains(&self, value: &VxdValue) -> bool {
+        match (self, value) {
+            (VxdAllowedValues::Strings(allowed), VxdValue::String(actual)) => {
+                allowed.iter().any(|a| a == actual)
+            }
+            (VxdAllowedValues::Chars(allowed), VxdValue::Char(actual)) => {
+                allowed.contains(actual)
+            }
+            _ => true, // The allowed-values check does not apply to this type.
+        }
+    }
+}
+
+/// The constraints declared for a single key.
+#[derive(Debug, Clone)]
+pub struct VxdFieldSchema {
+    pub expected_type: VxdType,
+    pub range: Option<VxdRange>,
+    pub allowed_values: Option<VxdAllowedValues>,
+    pub required: bool,
+}
+
+impl VxdFieldSchema {
+    /// Creates a field schema that only checks the expected type.
+    pub fn new(expected_type: VxdType) -> Self {
+        VxdFieldSchema {
+            expected_type,
+            range: None,
+            allowed_values: None,
+            required: false,
+        }
+    }
+
+    pub fn range(mut self, range: VxdRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    pub fn allowed_values(mut self, allowed_values: VxdAllowedValues) -> Self {
+        self.allowed_values = Some(allowed_values);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+}
+
+/// A single validation failure produced by [`VxdeParser::validate`](crate::VxdeParser::validate).
+#[derive(Debug, Clone, PartialEq)]
+pub enum VxdViolation {
+    /// A required key was missing from the parsed variables, or was present
+    /// only as `VxdValue::Null`.
+    MissingRequired { key: String },
+    /// The value at `key` did not match the declared `expected` type.
+    TypeMismatch {
+        key: String,
+        expected: VxdType,
+        found: VxdValue,
+    },
+    /// The value at `key` fell outside the declared numeric range.
+    OutOfRange {
+        key: String,
+        range: VxdRange,
+        found: VxdValue,
+    },
+    /// The value at `key` was not one of the declared allowed values.
+    NotAllowed {
+        key: String,
+        allowed: VxdAllowedValues,
+        found: VxdValue,
+    },
+}
+
+/// A collection of per-key constraints used to validate a parsed `.vxd` file.
+#[derive(Debug, Clone, Default)]
+pub struct VxdSchema {
+    fields: HashMap<String, VxdFieldSchema>,
+}
+
+impl VxdSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the constraints for `key`, replacing any existing declaration.
+    pub fn field(mut self, key: impl Into<String>, field: VxdFieldSchema) -> Self {
+        self.fields.insert(key.into(), field);
+        self
+    }
+
+    /// Checks `variables` against every declared field, returning every
+    /// violation found rather than stopping at the first one.
+    pub fn check(&self, variables: &HashMap<String, VxdValue>) -> Vec<VxdViolation> {
+        let mut violations = Vec::new();
+
+        for (key, field) in &self.fields {
+            match variables.get(key) {
+                None => {
+                    if field.required {
+                        violations.push(VxdViolation::MissingRequired { key: key.clone() });
+                    }
+                }
+                Some(VxdValue::Null) => {
+                    if field.required {
+                        violations.push(VxdViolation::MissingRequired { key: key.clone() });
+                    }
+                }
+                Some(value) => {
+                    if !field.expected_type.matches(value) {
+                        violations.push(VxdViolation::TypeMismatch {
+                            key: key.clone(),
+                            expected: field.expected_type.clone(),
+                            found: value.clone(),
+                        });
+                        continue;
+                    }
+
+                    if let Some(range) = field.range {
+                        if !range.contains(value) {
+                            violations.push(VxdViolation::OutOfRange {
+                                key: key.clone(),
+                                range,
+                                found: value.clone(),
+                            });
+                        }
+                    }
+
+                    if let Some(allowed) = &field.allowed_values {
+                        if !allowed.contains(value) {
+                            violations.push(VxdViolation::NotAllowed {
+                                key: key.clone(),
+                                allowed: allowed.clone(),
+                                found: value.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_field_missing() {
+        let schema = VxdSchema::new().field("PORT", VxdFieldSchema::new(VxdType::U32).required(true));
+        let variables = HashMap::new();
+        let violations = schema.check(&variables);
+        assert_eq!(violations, vec![VxdViolation::MissingRequired { key: "PORT".to_string() }]);
+    }
+
+    #[test]
+    fn test_range_violation() {
+        let schema = VxdSchema::new().field(
+            "PORT",
+            VxdFieldSchema::new(VxdType::U32).range(VxdRange::new().min(VxdBound::Inclusive(3000.0))),
+        );
+        let mut variables = HashMap::new();
+        variables.insert("PORT".to_string(), VxdValue::U32(80));
+
+        let violations = schema.check(&variables);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0], VxdViolation::OutOfRange { .. }));
+    }
+
+    #[test]
+    fn test_allowed_values_violation() {
+        let schema = VxdSchema::new().field(
+            "MODE",
+            VxdFieldSchema::new(VxdType::String).allowed_values(VxdAllowedValues::Strings(vec![
+                "always".to_string(),
+                "auto".to_string(),
+                "never".to_string(),
+            ])),
+        );
+        let mut variables = HashMap::new();
+        variables.insert("MODE".to_string(), VxdValue::String("sometimes".to_string()));
+
+        let violations = schema.check(&variables);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0], VxdViolation::NotAllowed { .. }));
+    }
+
+    #[test]
+    fn test_passes_with_no_violations() {
+        let schema = VxdSchema::new().field("PORT", VxdFieldSchema::new(VxdType::U32).required(true));
+        let mut variables = HashMap::new();
+        variables.insert("PORT".to_string(), VxdValue::U32(8080));
+
+        assert!(schema.check(&variables).is_empty());
+    }
+
+    /// `VxdType::Array` and `VxdType::Custom` let a schema constrain the
+    /// fields [`VxdValue::Array`] and [`VxdValue::Custom`] introduced after
+    /// this schema layer was first added, instead of always reporting them
+    /// as a type mismatch.
+    #[test]
+    fn test_array_and_custom_types_match() {
+        let schema = VxdSchema::new()
+            .field("PORTS", VxdFieldSchema::new(VxdType::Array))
+            .field("ADDR", VxdFieldSchema::new(VxdType::Custom("ipv4".to_string())));
+        let mut variables = HashMap::new();
+        variables.insert("PORTS".to_string(), VxdValue::Array(vec![VxdValue::U32(80)]));
+        variables.insert("ADDR".to_string(), VxdValue::Custom("ipv4".to_string(), "10.0.0.1".to_string()));
+
+        assert!(schema.check(&variables).is_empty());
+    }
+
+    /// A `VxdType::Custom` field only matches a `VxdValue::Custom` whose
+    /// registered type name matches exactly.
+    #[test]
+    fn test_custom_type_mismatch_on_different_tag() {
+        let schema = VxdSchema::new().field("ADDR", VxdFieldSchema::new(VxdType::Custom("ipv4".to_string())));
+        let mut variables = HashMap::new();
+        variables.insert("ADDR".to_string(), VxdValue::Custom("datetime".to_string(), "2024-01-01".to_string()));
+
+        let violations = schema.check(&variables);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0], VxdViolation::TypeMismatch { .. }));
+    }
+}
@@ -0,0 +1,310 @@
+//! Renders parsed variables back into canonical `.vxd` text, complementing
+//! [`VxdeParser`](crate::VxdeParser)'s read side. Strings and custom-type
+//! renderings containing newlines, semicolons, commas, or backslashes are
+//! escaped so that `from_file(write(x)) == x` holds for a round trip.
+//! Leading or trailing whitespace in a string, `char` values, and an array
+//! holding a single `Null` element are written as-is and do not round-trip:
+//! the parser trims the whitespace (so a whitespace `char` reads back as
+//! `Null`), a `;` or newline `char` breaks the declaration, a `,` `char`
+//! splits a `[char]` array element, and a lone `null` element reads back as
+//! a `Null` value rather than an array.
+
+use crate::VxdValue;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Escapes a string value so it can be embedded between `=` and `;` on a
+/// single `.vxd` line without being mistaken for a statement terminator, or
+/// for the `,` separator between elements of a `[type]` array.
+///
+/// A value whose content is exactly `null` or empty additionally gets a
+/// leading `\0` marker: those are otherwise indistinguishable, once written,
+/// from the bare `null`/empty-value text that [`crate::parse_scalar`] treats
+/// as the `VxdValue::Null` sentinel. `\0` is a zero-width escape that
+/// [`unescape_string`] consumes without emitting anything, so it has no
+/// effect beyond breaking that collision.
+pub(crate) fn escape_string(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len() + 2);
+    if raw == "null" || raw.is_empty() {
+        escaped.push_str("\\0");
+    }
+    for c in raw.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverses [`escape_string`], turning `\\`, `\n`, `\;` and `\,` back into a
+/// backslash, a newline, a semicolon, and a comma respectively, and dropping
+/// the zero-width `\0` marker entirely.
+pub(crate) fn unescape_string(escaped: &str) -> String {
+    let mut raw = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('0') => {}
+                Some('n') => raw.push('\n'),
+                Some(';') => raw.push(';'),
+                Some(',') => raw.push(','),
+                Some('\\') => raw.push('\\'),
+                Some(other) => {
+                    raw.push('\\');
+                    raw.push(other);
+                }
+                None => raw.push('\\'),
+            }
+        } else {
+            raw.push(c);
+        }
+    }
+    raw
+}
+
+/// The base `.vxd` type tag for a scalar `VxdValue`. Not meaningful for `Array`.
+fn scalar_type_tag(value: &VxdValue) -> String {
+    match value {
+        // The declared type of a `Null` value can't be recovered from the
+        // value alone, so it is rendered as a `string` placeholder.
+        VxdValue::String(_) | VxdValue::Null => "string".to_string(),
+        VxdValue::I32(_) => "i32".to_string(),
+        VxdValue::I64(_) => "i64".to_string(),
+        VxdValue::U32(_) => "u32".to_string(),
+        VxdValue::U64(_) => "u64".to_string(),
+        VxdValue::F32(_) => "f32".to_string(),
+        VxdValue::F64(_) => "f64".to_string(),
+        VxdValue::Bool(_) => "bool".to_string(),
+        VxdValue::Char(_) => "char".to_string(),
+        VxdValue::Array(_) => "string".to_string(), // Arrays of arrays are not supported.
+        VxdValue::Custom(type_name, _) => type_name.clone(),
+    }
+}
+
+/// Renders the value portion of a scalar `VxdValue` (everything between `=`
+/// and `;`, without its type tag).
+fn render_scalar(value: &VxdValue) -> String {
+    match value {
+        VxdValue::String(v) => escape_string(v),
+        VxdValue::I32(v) => v.to_string(),
+        VxdValue::I64(v) => v.to_string(),
+        VxdValue::U32(v) => v.to_string(),
+        VxdValue::U64(v) => v.to_string(),
+        VxdValue::F32(v) => v.to_string(),
+        VxdValue::F64(v) => v.to_string(),
+        VxdValue::Bool(v) => v.to_string(),
+        VxdValue::Char(v) => v.to_string(),
+        VxdValue::Null => "null".to_string(),
+        VxdValue::Array(_) => String::new(), // Arrays of arrays are not supported.
+        // Escaped the same way as `String`, so a canonical rendering that
+        // contains `;`, `,`, or a newline doesn't corrupt the declaration
+        // it's embedded in; see `parse_scalar`'s custom-type dispatch, which
+        // unescapes before handing the raw text to the registered closure.
+        VxdValue::Custom(_, rendered) => escape_string(rendered),
+    }
+}
+
+/// The declared `.vxd` type tag and rendered value for a `VxdValue`, including
+/// the `[elem_type]` tag used for `Array`.
+fn render_value(value: &VxdValue) -> (String, String) {
+    match value {
+        VxdValue::Array(items) => {
+            if items.is_empty() {
+                // An empty array has no element to infer a tag from, and
+                // rendering an empty value (`= ;`) would be indistinguishable
+                // from `Null` on read, so it gets its own literal `[]` marker.
+                return ("[string]".to_string(), "[]".to_string());
+            }
+
+            // The tag is taken from the first non-`Null` element, since an
+            // element can individually fall back to `Null` (e.g. a literal
+            // `null` entry) without the whole array being untyped.
+            let elem_tag = items
+                .iter()
+                .find(|item| !matches!(item, VxdValue::Null))
+                .map(scalar_type_tag)
+                .unwrap_or_else(|| "string".to_string());
+            let rendered = items.iter().map(render_scalar).collect::<Vec<_>>().join(", ");
+            (format!("[{}]", elem_tag), rendered)
+        }
+        _ => (scalar_type_tag(value), render_scalar(value)),
+    }
+}
+
+/// Renders `variables` as canonical `.vxd` text, one `NAME: type = value;`
+/// line per entry.
+///
+/// # Example
+///
+/// ```rust
+/// let text = vxde::writer::to_string(parser.get_variables());
+/// ```
+pub fn to_string(variables: &HashMap<String, VxdValue>) -> String {
+    let mut text = String::new();
+    for (name, value) in variables {
+        let (type_name, rendered) = render_value(value);
+        text.push_str(&format!("{}: {} = {};\n", name, type_name, rendered));
+    }
+    text
+}
+
+/// Renders `variables` followed by each of `sections` under a `[section_name]`
+/// header, the way [`VxdeParser`](crate::VxdeParser) groups declarations that
+/// followed such a header while parsing.
+///
+/// # Example
+///
+/// ```rust
+/// let text = vxde::writer::to_string_with_sections(parser.get_variables(), parser.get_sections());
+/// ```
+pub fn to_string_with_sections(
+    variables: &HashMap<String, VxdValue>,
+    sections: &HashMap<String, HashMap<String, VxdValue>>,
+) -> String {
+    let mut text = to_string(variables);
+    for (name, section_variables) in sections {
+        text.push('\n');
+        text.push_str(&format!("[{}]\n", name));
+        text.push_str(&to_string(section_variables));
+    }
+    text
+}
+
+/// Renders `variables` and writes the result to `path`, overwriting any
+/// existing file.
+///
+/// # Example
+///
+/// ```rust
+/// vxde::writer::to_file(parser.get_variables(), "config.vxd")?;
+/// ```
+pub fn to_file(variables: &HashMap<String, VxdValue>, path: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(path, to_string(variables))
+}
+
+/// Renders `variables` and `sections` via [`to_string_with_sections`] and
+/// writes the result to `path`, overwriting any existing file.
+///
+/// # Example
+///
+/// ```rust
+/// vxde::writer::to_file_with_sections(parser.get_variables(), parser.get_sections(), "config.vxd")?;
+/// ```
+pub fn to_file_with_sections(
+    variables: &HashMap<String, VxdValue>,
+    sections: &HashMap<String, HashMap<String, VxdValue>>,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    fs::write(path, to_string_with_sections(variables, sections))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VxdeParser;
+
+    #[test]
+    fn test_escape_round_trip() {
+        let raw = "line one\nline two; with semicolon and a \\backslash";
+        assert_eq!(unescape_string(&escape_string(raw)), raw);
+    }
+
+    #[test]
+    fn test_write_then_parse_round_trip() {
+        let mut variables = HashMap::new();
+        variables.insert("NAME".to_string(), VxdValue::String("reanore\nvxde".to_string()));
+        variables.insert("PORT".to_string(), VxdValue::U32(8080));
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::from_str(&text).expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+
+    /// A string whose content is literally `"null"` or `""` must round-trip
+    /// as that string, not collapse into `VxdValue::Null`.
+    #[test]
+    fn test_string_values_that_look_like_null_round_trip() {
+        let mut variables = HashMap::new();
+        variables.insert("A".to_string(), VxdValue::String("null".to_string()));
+        variables.insert("B".to_string(), VxdValue::String(String::new()));
+        variables.insert("C".to_string(), VxdValue::Null);
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::from_str(&text).expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+
+    /// An array containing a `Null` element (e.g. from a literal `null`
+    /// entry) must still round-trip with its real element type, and an
+    /// empty array must come back as an empty array rather than `Null`.
+    #[test]
+    fn test_array_round_trip_with_null_element_and_empty_array() {
+        let mut variables = HashMap::new();
+        variables.insert("PORTS".to_string(), VxdValue::Array(vec![VxdValue::Null, VxdValue::U32(5), VxdValue::U32(9)]));
+        variables.insert("TAGS".to_string(), VxdValue::Array(Vec::new()));
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::from_str(&text).expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+
+    /// A string array element containing a literal comma must not be split
+    /// into two elements on round trip.
+    #[test]
+    fn test_array_round_trip_escapes_commas_in_elements() {
+        let mut variables = HashMap::new();
+        variables.insert(
+            "PAIRS".to_string(),
+            VxdValue::Array(vec![VxdValue::String("a,b".to_string()), VxdValue::String("c".to_string())]),
+        );
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::from_str(&text).expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+
+    /// A `[string]` array element whose content is literally `"null"` or
+    /// `""` must round-trip as that element, not collapse into a `Null`
+    /// element, the same collision `escape_string`'s `\0` marker already
+    /// prevents for top-level string declarations.
+    #[test]
+    fn test_array_string_elements_that_look_like_null_round_trip() {
+        let mut variables = HashMap::new();
+        variables.insert(
+            "TAGS".to_string(),
+            VxdValue::Array(vec![VxdValue::String("null".to_string()), VxdValue::String(String::new()), VxdValue::String("foo".to_string())]),
+        );
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::from_str(&text).expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+
+    /// A registered custom type's canonical rendering must be escaped, the
+    /// same as a `String`, so a `;` or `,` embedded in it doesn't corrupt
+    /// the rest of the declaration or get split as an array separator.
+    #[test]
+    fn test_custom_value_round_trip_escapes_reserved_characters() {
+        let mut variables = HashMap::new();
+        variables.insert("WEIRD".to_string(), VxdValue::Custom("odd".to_string(), "a;b,c".to_string()));
+
+        let text = to_string(&variables);
+        let parser = VxdeParser::with_custom_type("odd", |raw| Ok(VxdValue::Custom("odd".to_string(), raw.to_string())))
+            .from_str(&text)
+            .expect("rendered .vxd text should parse");
+
+        assert_eq!(parser.get_variables(), &variables);
+    }
+}